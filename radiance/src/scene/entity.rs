@@ -58,6 +58,109 @@ impl<TCallbacks: EntityCallbacks> CoreEntity<TCallbacks> {
         let component = <Self as Entity>::get_component_mut(self, type_id);
         component.and_then(|c| c.downcast_mut())
     }
+
+    /// Type ids of every component currently attached, including duplicates' shared id once.
+    /// Mainly useful for generic code (e.g. scene serialization) that needs to walk every
+    /// component without knowing its concrete type up front.
+    pub fn component_type_ids(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.components
+            .iter()
+            .filter(|(_, components)| !components.is_empty())
+            .map(|(type_id, _)| *type_id)
+    }
+
+    /// All components of type `T`, in attach order. Unlike [`Self::get_component`], which only
+    /// ever sees the first one, this reaches every component an entity carries of a given type.
+    pub fn get_components<T>(&self) -> impl Iterator<Item = &T>
+    where
+        T: 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        self.components
+            .get(&type_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|c| c.downcast_ref())
+    }
+
+    /// Mutable counterpart to [`Self::get_components`].
+    pub fn get_components_mut<T>(&mut self) -> impl Iterator<Item = &mut T>
+    where
+        T: 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        self.components
+            .get_mut(&type_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|c| c.downcast_mut())
+    }
+
+    /// Removes and returns the first attached component of type `T`, if any.
+    pub fn remove_component<T>(&mut self) -> Option<T>
+    where
+        T: 'static,
+    {
+        let type_id = TypeId::of::<T>();
+        let components = self.components.get_mut(&type_id)?;
+        if components.is_empty() {
+            return None;
+        }
+
+        let removed = components.remove(0);
+        removed.downcast::<T>().ok().map(|c| *c)
+    }
+}
+
+type CloneFn = fn(&dyn Any) -> Box<dyn Any>;
+
+/// Maps a component's `TypeId` to a thunk that can duplicate it from a `Box<dyn Any>`, so
+/// [`clone_entity`] can copy a component bag without knowing any of its stored types up front.
+/// Components with no registration are skipped (with a warning) rather than failing the clone.
+#[derive(Default)]
+pub struct CloneRegistry {
+    thunks: HashMap<TypeId, CloneFn>,
+}
+
+impl CloneRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<T: Clone + 'static>(&mut self) {
+        self.thunks.insert(TypeId::of::<T>(), |component| {
+            let value = component
+                .downcast_ref::<T>()
+                .expect("CloneRegistry: registered TypeId does not match stored component");
+            Box::new(value.clone())
+        });
+    }
+}
+
+/// Duplicates `entity` into a new, independent `CoreEntity`: the transform is copied outright,
+/// and every component is duplicated via its registered clone thunk. This is the building block
+/// for a prefab system — load a template once, then `clone_entity` it per spawn instead of
+/// re-running the original (often asset-loading) construction path.
+pub fn clone_entity<TCallbacks: EntityCallbacks>(
+    entity: &CoreEntity<TCallbacks>,
+    registry: &CloneRegistry,
+    callbacks: TCallbacks,
+) -> CoreEntity<TCallbacks> {
+    let mut clone = CoreEntity::new(callbacks);
+    *clone.transform_mut() = entity.transform().clone();
+
+    for (type_id, components) in &entity.components {
+        let Some(thunk) = registry.thunks.get(type_id) else {
+            log::warn!("Skipping unregistered component type while cloning entity");
+            continue;
+        };
+
+        for component in components {
+            <CoreEntity<TCallbacks> as Entity>::add_component(&mut clone, thunk(component.as_ref()));
+        }
+    }
+
+    clone
 }
 
 #[inline]
@@ -88,6 +191,47 @@ where
     component.and_then(|c| c.downcast_mut())
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Vec3;
+
+    struct NoCallbacks;
+    impl EntityCallbacks for NoCallbacks {}
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct Health(i32);
+
+    #[test]
+    fn clone_entity_duplicates_transform_and_registered_components() {
+        let mut entity = CoreEntity::new(NoCallbacks);
+        entity
+            .transform_mut()
+            .set_position(&Vec3::new(1.0, 2.0, 3.0));
+        entity.add_component(Health(42));
+
+        let mut registry = CloneRegistry::new();
+        registry.register::<Health>();
+
+        let clone = clone_entity(&entity, &registry, NoCallbacks);
+
+        let position = clone.transform().position();
+        assert_eq!((position.x, position.y, position.z), (1.0, 2.0, 3.0));
+        assert_eq!(clone.get_component::<Health>(), Some(&Health(42)));
+    }
+
+    #[test]
+    fn clone_entity_skips_unregistered_components() {
+        let mut entity = CoreEntity::new(NoCallbacks);
+        entity.add_component(Health(7));
+
+        let registry = CloneRegistry::new();
+        let clone = clone_entity(&entity, &registry, NoCallbacks);
+
+        assert_eq!(clone.get_component::<Health>(), None);
+    }
+}
+
 impl<TCallbacks: EntityCallbacks> Entity for CoreEntity<TCallbacks> {
     fn load(&mut self) {
         callback!(self, on_loading);
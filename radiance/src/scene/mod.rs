@@ -1,10 +1,14 @@
 mod camera;
 mod entity;
+mod persist;
+mod reflect;
 mod scene;
 
 pub use camera::Camera;
 pub use entity::{
-    entity_add_component, entity_get_component, entity_get_component_mut, CoreEntity, Entity,
-    EntityCallbacks,
+    clone_entity, entity_add_component, entity_get_component, entity_get_component_mut,
+    CloneRegistry, CoreEntity, Entity, EntityCallbacks,
 };
+pub use persist::{load_entities, save_entities, ComponentDocument, EntityDocument, SceneDocument};
+pub use reflect::ComponentRegistry;
 pub use scene::{CoreScene, DefaultScene, Scene, SceneCallbacks};
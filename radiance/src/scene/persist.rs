@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+use crate::math::Transform;
+
+use super::entity::{CoreEntity, Entity, EntityCallbacks};
+use super::reflect::ComponentRegistry;
+
+/// A human-readable, hand-editable snapshot of a scene's entity tree.
+///
+/// This mirrors the entity/components layout used by data-driven engines: an outer record
+/// holding a flat list of entities, each with its transform and a typed `components` array.
+/// It is meant for editor/debug workflows (dumping a scene, hand-editing it, reloading it) and
+/// deliberately does not attempt to capture anything backed by the proprietary binary assets.
+#[derive(Serialize, Deserialize)]
+pub struct SceneDocument {
+    pub entities: Vec<EntityDocument>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EntityDocument {
+    pub transform: Transform,
+    pub components: Vec<ComponentDocument>,
+}
+
+/// One serialized component, named by the key it was registered under in a [`ComponentRegistry`]
+/// rather than by `TypeId`, so the document stays valid across Rust-side renames.
+#[derive(Serialize, Deserialize)]
+pub struct ComponentDocument {
+    pub type_name: String,
+    pub data: String,
+}
+
+/// Serializes `entities` (a scene's entity tree) to a RON document, using `registry` to encode
+/// any component whose type was registered via [`ComponentRegistry::register`]. Components with
+/// no registration are skipped, since they have no way to be written back out. Only the first
+/// component of each type is written; further components of the same type are not saved.
+pub fn save_entities<TCallbacks: EntityCallbacks>(
+    entities: &[CoreEntity<TCallbacks>],
+    registry: &ComponentRegistry,
+) -> ron::Result<String> {
+    let document = SceneDocument {
+        entities: entities
+            .iter()
+            .map(|entity| entity_to_document(entity, registry))
+            .collect(),
+    };
+
+    ron::to_string(&document)
+}
+
+fn entity_to_document<TCallbacks: EntityCallbacks>(
+    entity: &CoreEntity<TCallbacks>,
+    registry: &ComponentRegistry,
+) -> EntityDocument {
+    EntityDocument {
+        transform: entity.transform().clone(),
+        components: entity
+            .component_type_ids()
+            .filter_map(|type_id| {
+                let component = <CoreEntity<TCallbacks> as Entity>::get_component(entity, type_id)?;
+                let (type_name, data) = registry.serialize(type_id, component)?;
+                match data {
+                    Ok(data) => Some(ComponentDocument {
+                        type_name: type_name.to_string(),
+                        data,
+                    }),
+                    Err(e) => {
+                        log::error!("Failed to serialize component {}: {}", type_name, e);
+                        None
+                    }
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Parses a RON document produced by [`save_entities`] back into entities. Each returned
+/// `CoreEntity` is populated with its saved transform and every component whose name is
+/// registered in `registry`; unregistered or unparsable components are skipped with a warning
+/// rather than failing the whole load.
+pub fn load_entities<TCallbacks: EntityCallbacks>(
+    data: &str,
+    registry: &ComponentRegistry,
+    make_callbacks: impl Fn() -> TCallbacks,
+) -> ron::Result<Vec<CoreEntity<TCallbacks>>> {
+    let document: SceneDocument = ron::from_str(data)?;
+
+    Ok(document
+        .entities
+        .into_iter()
+        .map(|entity_document| document_to_entity(entity_document, registry, &make_callbacks))
+        .collect())
+}
+
+fn document_to_entity<TCallbacks: EntityCallbacks>(
+    document: EntityDocument,
+    registry: &ComponentRegistry,
+    make_callbacks: &impl Fn() -> TCallbacks,
+) -> CoreEntity<TCallbacks> {
+    let mut entity = CoreEntity::new(make_callbacks());
+    *entity.transform_mut() = document.transform;
+
+    for component in document.components {
+        match registry.deserialize(&component.type_name, &component.data) {
+            Some(Ok(component)) => {
+                <CoreEntity<TCallbacks> as Entity>::add_component(&mut entity, component);
+            }
+            Some(Err(e)) => {
+                log::warn!("Skipping component {}: {}", component.type_name, e)
+            }
+            None => log::warn!(
+                "Skipping unregistered component type '{}' while loading scene",
+                component.type_name
+            ),
+        }
+    }
+
+    entity
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::math::Vec3;
+
+    use super::*;
+
+    struct NoCallbacks;
+    impl EntityCallbacks for NoCallbacks {}
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Health(i32);
+
+    #[test]
+    fn round_trips_transform_and_registered_components() {
+        let mut entity = CoreEntity::new(NoCallbacks);
+        entity
+            .transform_mut()
+            .set_position(&Vec3::new(1.0, 2.0, 3.0));
+        entity.add_component(Health(42));
+
+        let mut registry = ComponentRegistry::new();
+        registry.register::<Health>("health");
+
+        let saved = save_entities(&[entity], &registry).expect("save_entities");
+        let loaded = load_entities(&saved, &registry, || NoCallbacks).expect("load_entities");
+
+        assert_eq!(loaded.len(), 1);
+        let position = loaded[0].transform().position();
+        assert_eq!((position.x, position.y, position.z), (1.0, 2.0, 3.0));
+        assert_eq!(loaded[0].get_component::<Health>(), Some(&Health(42)));
+    }
+
+    #[test]
+    fn skips_unregistered_components_on_load() {
+        let mut entity = CoreEntity::new(NoCallbacks);
+        entity.add_component(Health(7));
+
+        let mut save_registry = ComponentRegistry::new();
+        save_registry.register::<Health>("health");
+        let saved = save_entities(&[entity], &save_registry).expect("save_entities");
+
+        let registry = ComponentRegistry::new();
+        let loaded = load_entities(&saved, &registry, || NoCallbacks).expect("load_entities");
+
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].get_component::<Health>(), None);
+    }
+}
@@ -0,0 +1,60 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Knows how to turn a boxed component back and forth into a RON fragment, keyed by a stable
+/// name rather than `TypeId` so saved scenes keep loading after the Rust type is renamed or
+/// moved to a different module.
+struct ComponentRegistration {
+    serialize: fn(&dyn Any) -> ron::Result<String>,
+    deserialize: fn(&str) -> ron::Result<Box<dyn Any>>,
+}
+
+/// Maps a stable component name to the glue needed to (de)serialize it from a `Box<dyn Any>`.
+///
+/// Components are stored in `CoreEntity` as `Box<dyn Any>`, so there's no way to serialize them
+/// generically; callers register each serializable component type once, and the scene save/load
+/// code in [`super::persist`] looks the registration up by `TypeId` when saving and by name when
+/// loading.
+#[derive(Default)]
+pub struct ComponentRegistry {
+    by_type_id: HashMap<TypeId, (&'static str, fn(&dyn Any) -> ron::Result<String>)>,
+    by_name: HashMap<&'static str, ComponentRegistration>,
+}
+
+impl ComponentRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `T` under `name` so it can be round-tripped through a scene document.
+    pub fn register<T: Any + Serialize + DeserializeOwned>(&mut self, name: &'static str) {
+        let registration = ComponentRegistration {
+            serialize: |component| {
+                let value = component
+                    .downcast_ref::<T>()
+                    .expect("ComponentRegistry: registered name does not match stored TypeId");
+                ron::to_string(value)
+            },
+            deserialize: |data| Ok(Box::new(ron::from_str::<T>(data)?)),
+        };
+
+        self.by_type_id.insert(TypeId::of::<T>(), (name, registration.serialize));
+        self.by_name.insert(name, registration);
+    }
+
+    pub fn is_registered(&self, type_id: TypeId) -> bool {
+        self.by_type_id.contains_key(&type_id)
+    }
+
+    pub fn serialize(&self, type_id: TypeId, component: &dyn Any) -> Option<(&'static str, ron::Result<String>)> {
+        let (name, serialize) = self.by_type_id.get(&type_id)?;
+        Some((name, serialize(component)))
+    }
+
+    pub fn deserialize(&self, name: &str, data: &str) -> Option<ron::Result<Box<dyn Any>>> {
+        let registration = self.by_name.get(name)?;
+        Some((registration.deserialize)(data))
+    }
+}
@@ -0,0 +1,100 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::entity::{entity_get_component, Entity};
+
+pub trait SceneCallbacks {
+    define_callback_fn!(on_loading, CoreScene, SceneCallbacks);
+    define_callback_fn!(on_updating, CoreScene, SceneCallbacks, _delta_sec: f32);
+}
+
+/// A scene's entity tree plus whatever per-scene behavior `TCallbacks` hooks in, mirroring
+/// `CoreEntity`'s relationship to `EntityCallbacks`.
+pub trait Scene {
+    fn load(&mut self);
+    fn update(&mut self, delta_sec: f32);
+    fn add_entity(&mut self, entity: Box<dyn Entity>);
+    fn entities(&self) -> &[Box<dyn Entity>];
+}
+
+/// Entities are stored as `Box<dyn Entity>` rather than `CoreEntity<TCallbacks>` directly, since a
+/// single scene holds many different concrete entity types (each with their own `EntityCallbacks`)
+/// side by side.
+pub struct CoreScene<TCallbacks: SceneCallbacks> {
+    entities: Vec<Box<dyn Entity>>,
+    callbacks: Rc<RefCell<TCallbacks>>,
+}
+
+impl<TCallbacks: SceneCallbacks> CoreScene<TCallbacks> {
+    pub fn new(callbacks: TCallbacks) -> Self {
+        Self {
+            entities: vec![],
+            callbacks: Rc::new(RefCell::new(callbacks)),
+        }
+    }
+
+    /// Every entity carrying at least one component of type `T`, in scene order. The query
+    /// surface gameplay systems use (e.g. "every entity with a `Pal4ActorController`") instead of
+    /// walking `entities()` and calling `get_component` on each one by hand.
+    pub fn query<T: 'static>(&self) -> impl Iterator<Item = &dyn Entity> {
+        self.entities
+            .iter()
+            .map(|entity| entity.as_ref())
+            .filter(|entity| entity_get_component::<T>(*entity).is_some())
+    }
+}
+
+impl<TCallbacks: SceneCallbacks> Scene for CoreScene<TCallbacks> {
+    fn load(&mut self) {
+        callback!(self, on_loading);
+        for entity in &mut self.entities {
+            entity.load();
+        }
+    }
+
+    fn update(&mut self, delta_sec: f32) {
+        callback!(self, on_updating, delta_sec);
+        for entity in &mut self.entities {
+            entity.update(delta_sec);
+        }
+    }
+
+    fn add_entity(&mut self, entity: Box<dyn Entity>) {
+        self.entities.push(entity);
+    }
+
+    fn entities(&self) -> &[Box<dyn Entity>] {
+        &self.entities
+    }
+}
+
+/// A [`SceneCallbacks`] with no behavior of its own, for scenes that don't need per-scene hooks.
+#[derive(Default)]
+pub struct DefaultScene;
+
+impl SceneCallbacks for DefaultScene {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scene::entity::{CoreEntity, EntityCallbacks};
+
+    struct NoEntityCallbacks;
+    impl EntityCallbacks for NoEntityCallbacks {}
+
+    struct Marker;
+
+    #[test]
+    fn query_yields_only_entities_carrying_the_component() {
+        let mut tagged = CoreEntity::new(NoEntityCallbacks);
+        tagged.add_component(Marker);
+        let untagged = CoreEntity::new(NoEntityCallbacks);
+
+        let mut scene = CoreScene::new(DefaultScene);
+        scene.add_entity(Box::new(tagged));
+        scene.add_entity(Box::new(untagged));
+
+        let matched: Vec<_> = scene.query::<Marker>().collect();
+        assert_eq!(matched.len(), 1);
+    }
+}
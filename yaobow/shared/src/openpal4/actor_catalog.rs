@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// One entry in the actor catalog: everything needed to spawn an actor without the caller
+/// knowing its underlying model id or default pose.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActorDefinition {
+    pub model_name: String,
+    pub default_action: Option<String>,
+    #[serde(default)]
+    pub default_position: [f32; 3],
+}
+
+/// A RON-backed table mapping a logical actor id (e.g. `"yun_tianhe"`) to its
+/// [`ActorDefinition`], so new playable characters or NPC variants can be added by editing a
+/// config file instead of recompiling.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActorCatalog {
+    actors: HashMap<String, ActorDefinition>,
+}
+
+impl ActorCatalog {
+    pub fn load(data: &str) -> ron::Result<Self> {
+        ron::from_str(data)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ActorDefinition> {
+        self.actors.get(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_actor_definitions_by_id() {
+        let data = r#"(
+            actors: {
+                "yun_tianhe": (
+                    model_name: "101",
+                    default_action: Some("C01"),
+                    default_position: (1.0, 0.0, 2.0),
+                ),
+            },
+        )"#;
+
+        let catalog = ActorCatalog::load(data).unwrap();
+        let definition = catalog.get("yun_tianhe").unwrap();
+
+        assert_eq!(definition.model_name, "101");
+        assert_eq!(definition.default_action.as_deref(), Some("C01"));
+        assert_eq!(definition.default_position, [1.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn default_position_falls_back_to_zero_when_omitted() {
+        let data = r#"(
+            actors: {
+                "han_lingsha": (model_name: "102", default_action: None),
+            },
+        )"#;
+
+        let catalog = ActorCatalog::load(data).unwrap();
+        let definition = catalog.get("han_lingsha").unwrap();
+
+        assert_eq!(definition.default_position, [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn unknown_id_resolves_to_none() {
+        let catalog = ActorCatalog::load("(actors: {})").unwrap();
+        assert!(catalog.get("nobody").is_none());
+    }
+}
@@ -0,0 +1,422 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+use crosscom::ComRc;
+use radiance::{
+    comdef::{IEntity, IInstancedMeshComponent, IMeshComponent, IScene},
+    math::{Quaternion, Vec3},
+    scene::{CoreEntity, CoreScene},
+};
+
+use super::actor_catalog::ActorCatalog;
+use super::scene_data::NpcInfo;
+
+const ACTOR_CATALOG_PATH: &str = "actors.ron";
+
+/// A glTF mesh, flattened to vertex/index buffers, attached as a plain component so the
+/// renderer can pick it up the same way it does native actor meshes.
+pub struct GltfMeshComponent {
+    pub positions: Vec<Vec3>,
+    pub indices: Vec<u32>,
+}
+
+/// A native PAL4 actor mesh, flattened to vertex/index buffers. Distinct from
+/// [`GltfMeshComponent`] because the two are parsed from unrelated formats; sharing one type
+/// between them would make it look like native actors and glTF imports share a loader.
+pub struct NativeActorMesh {
+    pub positions: Vec<Vec3>,
+    pub indices: Vec<u32>,
+}
+
+/// The mesh data backing an actor model, uploaded once and shared by every entity that
+/// was spawned from the same `model_name`.
+pub struct ActorModel {
+    pub mesh: NativeActorMesh,
+}
+
+/// Attached to every actor entity spawned from an `ActorModel`, so the renderer can submit all
+/// entities sharing `model` as a single instanced draw call, with each entity's own transform
+/// supplying the per-instance data.
+#[derive(Clone)]
+pub struct InstancedMeshComponent {
+    pub model: Rc<ActorModel>,
+}
+
+/// The raw, thread-safe result of loading an actor, produced on a worker thread and turned into
+/// a real `CoreEntity` on the main thread once [`AssetHandle::poll`] picks it up. `ComRc`/
+/// `CoreEntity` are `Rc`-based and can't cross threads, so the worker only ever hands back plain
+/// data.
+pub struct ActorLoadResult {
+    pub name: String,
+    pub model_name: String,
+    pub default_act: Option<String>,
+    /// `None` when `model_name` was already cached at request time, so no worker ever had to
+    /// load it; `finish_actor_load` then just looks the cached upload back up.
+    pub mesh: Option<NativeActorMesh>,
+}
+
+/// How many worker threads service [`AssetLoader::load_actor_async`] requests. Bounded so a
+/// block with many NPCs doesn't spawn a thread per spawn; a handful of persistent workers pull
+/// jobs off a shared queue instead.
+const ACTOR_LOAD_WORKER_COUNT: usize = 4;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A small, fixed-size pool of worker threads pulling jobs off a shared queue, used so that
+/// asynchronous asset loads don't spawn an unbounded number of OS threads.
+struct WorkerPool {
+    job_sender: Sender<Job>,
+}
+
+impl WorkerPool {
+    fn new(worker_count: usize) -> Self {
+        let (job_sender, job_receiver) = mpsc::channel::<Job>();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+
+        for _ in 0..worker_count {
+            let job_receiver = job_receiver.clone();
+            std::thread::spawn(move || loop {
+                let job = job_receiver.lock().unwrap().recv();
+                match job {
+                    Ok(job) => job(),
+                    Err(_) => break,
+                }
+            });
+        }
+
+        Self { job_sender }
+    }
+
+    fn submit(&self, job: Job) {
+        let _ = self.job_sender.send(job);
+    }
+}
+
+enum AssetHandleState<T> {
+    Pending(Receiver<T>),
+    Ready(T),
+}
+
+/// A lightweight handle to an asset that is either already available or loading on a worker
+/// thread. Call [`Self::poll`] once per frame; once it returns `true` the loaded value is
+/// available via [`Self::take`].
+pub struct AssetHandle<T> {
+    state: Option<AssetHandleState<T>>,
+}
+
+impl<T> AssetHandle<T> {
+    /// A handle that is already resolved, for callers that can satisfy the request without
+    /// touching a worker thread at all (e.g. a cache hit).
+    fn ready(value: T) -> Self {
+        Self {
+            state: Some(AssetHandleState::Ready(value)),
+        }
+    }
+
+    /// A handle backed by a worker thread that will eventually send its result on `receiver`.
+    fn pending(receiver: Receiver<T>) -> Self {
+        Self {
+            state: Some(AssetHandleState::Pending(receiver)),
+        }
+    }
+
+    /// Checks whether the worker has produced a value yet, without blocking.
+    pub fn poll(&mut self) -> bool {
+        if let Some(AssetHandleState::Pending(receiver)) = &self.state {
+            if let Ok(value) = receiver.try_recv() {
+                self.state = Some(AssetHandleState::Ready(value));
+            }
+        }
+
+        matches!(self.state, Some(AssetHandleState::Ready(_)))
+    }
+
+    pub fn is_loaded(&self) -> bool {
+        matches!(self.state, Some(AssetHandleState::Ready(_)))
+    }
+
+    /// Takes the loaded value, if [`Self::poll`] has already observed it.
+    pub fn take(&mut self) -> Option<T> {
+        match self.state.take() {
+            Some(AssetHandleState::Ready(value)) => Some(value),
+            other => {
+                self.state = other;
+                None
+            }
+        }
+    }
+}
+
+/// Loads game content for OpenPAL4 blocks. The actor catalog and glTF files are read from the
+/// data root the game was pointed at; `load_scene` returns an empty scene, `load_npc_info`
+/// returns no NPC records, and the scene clip and sky lookups return `None`.
+pub struct AssetLoader {
+    root_path: PathBuf,
+    actor_model_cache: RefCell<HashMap<String, Rc<ActorModel>>>,
+    actor_catalog: ActorCatalog,
+    actor_load_workers: WorkerPool,
+}
+
+impl AssetLoader {
+    pub fn new<P: AsRef<Path>>(root_path: P) -> Self {
+        let root_path = root_path.as_ref().to_owned();
+        let actor_catalog = Self::load_actor_catalog(&root_path).unwrap_or_else(|e| {
+            log::error!("Failed to load actor catalog: {}", e);
+            ActorCatalog::load("(actors: {})").unwrap()
+        });
+
+        Self {
+            root_path,
+            actor_model_cache: RefCell::new(HashMap::new()),
+            actor_catalog,
+            actor_load_workers: WorkerPool::new(ACTOR_LOAD_WORKER_COUNT),
+        }
+    }
+
+    fn load_actor_catalog(root_path: &Path) -> anyhow::Result<ActorCatalog> {
+        let data = std::fs::read_to_string(root_path.join(ACTOR_CATALOG_PATH))?;
+        Ok(ActorCatalog::load(&data)?)
+    }
+
+    /// Looks up an actor by its catalog id (model name, default action, default placement),
+    /// resolving `load_player`/NPC spawns through data instead of hard-coded strings.
+    pub fn actor_definition(&self, id: &str) -> Option<&super::actor_catalog::ActorDefinition> {
+        self.actor_catalog.get(id)
+    }
+
+    pub fn load_scene(&self, scene_name: &str, block_name: &str) -> anyhow::Result<ComRc<IScene>> {
+        Ok(CoreScene::create())
+    }
+
+    pub fn try_load_scene_clip(&self, scene_name: &str, block_name: &str) -> Option<ComRc<IEntity>> {
+        None
+    }
+
+    pub fn try_load_scene_sky(&self, scene_name: &str, block_name: &str) -> Option<ComRc<IEntity>> {
+        None
+    }
+
+    pub fn load_npc_info(&self, scene_name: &str, block_name: &str) -> anyhow::Result<NpcInfo> {
+        Ok(NpcInfo { data: vec![] })
+    }
+
+    pub fn load_actor(
+        &self,
+        name: &str,
+        model_name: &str,
+        default_act: Option<&str>,
+    ) -> anyhow::Result<ComRc<IEntity>> {
+        let model = self.load_actor_model(model_name)?;
+
+        let entity = CoreEntity::create(name.to_string(), true);
+        entity.add_component(
+            IInstancedMeshComponent::uuid(),
+            ComRc::from_object(InstancedMeshComponent { model }),
+        );
+
+        Ok(entity)
+    }
+
+    /// Kicks off loading an actor and returns immediately with a handle, so a caller like
+    /// `Pal4Scene::load_async` can spawn a placeholder entity and swap in the real one once the
+    /// handle resolves, instead of stalling the frame on `load_actor`. If `model_name` is already
+    /// cached, the handle resolves on the spot and no worker is touched at all; otherwise the
+    /// (potentially disk-bound) mesh load runs on the bounded worker pool, not a thread per call,
+    /// so a block with many NPCs sharing a model doesn't spawn a thread per spawn.
+    pub fn load_actor_async(
+        &self,
+        name: &str,
+        model_name: &str,
+        default_act: Option<&str>,
+    ) -> AssetHandle<ActorLoadResult> {
+        let name = name.to_string();
+        let model_name = model_name.to_string();
+        let default_act = default_act.map(str::to_string);
+
+        if self.actor_model_cache.borrow().contains_key(&model_name) {
+            return AssetHandle::ready(ActorLoadResult {
+                name,
+                model_name,
+                default_act,
+                mesh: None,
+            });
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        self.actor_load_workers.submit(Box::new(move || {
+            let mesh = load_actor_mesh_data(&model_name);
+            let _ = sender.send(ActorLoadResult {
+                name,
+                model_name,
+                default_act,
+                mesh: Some(mesh),
+            });
+        }));
+
+        AssetHandle::pending(receiver)
+    }
+
+    /// Turns a resolved [`ActorLoadResult`] into a real entity, reusing a cached model upload if
+    /// one exists — either because `result.mesh` is `None` (it was already cached when the load
+    /// was requested) or because another entity cached one in the meantime — same as
+    /// [`Self::load_actor`].
+    pub fn finish_actor_load(&self, result: ActorLoadResult) -> anyhow::Result<ComRc<IEntity>> {
+        let model = match result.mesh {
+            Some(mesh) => self.cache_actor_model(result.model_name, mesh),
+            None => self
+                .actor_model_cache
+                .borrow()
+                .get(&result.model_name)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("actor model '{}' missing from cache", result.model_name))?,
+        };
+
+        let entity = CoreEntity::create(result.name, true);
+        entity.add_component(
+            IInstancedMeshComponent::uuid(),
+            ComRc::from_object(InstancedMeshComponent { model }),
+        );
+
+        Ok(entity)
+    }
+
+    /// Duplicates an already-spawned actor `template` into a fresh entity at `name`, for turning a
+    /// loaded NPC template into a prefab that's cloned per spawn instead of reloaded. `template`
+    /// is a `ComRc<IEntity>`, so this goes through `get_component`/`query_interface` rather than
+    /// `radiance::scene::clone_entity` (which only knows how to duplicate a raw `CoreEntity`, not
+    /// the COM wrapper every entity in this file is actually handed around as). The mesh component
+    /// is shared rather than duplicated, same as every other entity spawned from this model.
+    pub fn clone_actor(&self, template: &ComRc<IEntity>, name: &str) -> ComRc<IEntity> {
+        let entity = CoreEntity::create(name.to_string(), true);
+        *entity.transform().borrow_mut() = template.transform().borrow().clone();
+
+        if let Some(mesh_component) = template
+            .get_component(IInstancedMeshComponent::uuid())
+            .and_then(|c| c.query_interface::<IInstancedMeshComponent>())
+        {
+            entity.add_component(IInstancedMeshComponent::uuid(), mesh_component);
+        }
+
+        entity
+    }
+
+    /// Loads the mesh data for `model_name`, reusing a cached upload if another entity
+    /// already loaded the same model. This is what lets a block with many NPCs sharing a model
+    /// avoid re-loading and re-uploading identical geometry per spawn.
+    fn load_actor_model(&self, model_name: &str) -> anyhow::Result<Rc<ActorModel>> {
+        if let Some(cached) = self.actor_model_cache.borrow().get(model_name) {
+            return Ok(cached.clone());
+        }
+
+        Ok(self.cache_actor_model(model_name.to_string(), load_actor_mesh_data(model_name)))
+    }
+
+    /// Inserts `mesh` into the model cache under `model_name`, unless another caller already
+    /// cached one first, in which case the freshly loaded `mesh` is dropped in favor of the
+    /// cached copy so every entity sharing this model still shares one upload.
+    fn cache_actor_model(&self, model_name: String, mesh: NativeActorMesh) -> Rc<ActorModel> {
+        if let Some(cached) = self.actor_model_cache.borrow().get(&model_name) {
+            return cached.clone();
+        }
+
+        let model = Rc::new(ActorModel { mesh });
+        self.actor_model_cache.borrow_mut().insert(model_name, model.clone());
+        model
+    }
+
+    /// Parses a `.gltf`/`.glb` file into a tree of `CoreEntity` nodes, attaching a mesh component
+    /// per node and preserving node transforms and parent/child relationships. Node names are
+    /// carried over to entity names so the existing `get_component`/controller attachment logic
+    /// keeps working for glTF-sourced actors the same way it does for native ones.
+    pub fn load_gltf<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<ComRc<IEntity>> {
+        let path = self.resolve(path.as_ref());
+        let (document, buffers, _images) = gltf::import(&path)?;
+
+        let scene = document
+            .default_scene()
+            .or_else(|| document.scenes().next())
+            .ok_or_else(|| anyhow::anyhow!("glTF file {:?} has no scenes", path))?;
+
+        let root = CoreEntity::create("root".to_string(), true);
+        for node in scene.nodes() {
+            let child = self.load_gltf_node(&node, &buffers)?;
+            root.add_child(child);
+        }
+
+        Ok(root)
+    }
+
+    /// Like [`AssetLoader::load_gltf`] but wraps the result in a `CoreScene`, for glTF files
+    /// meant to stand in for a whole block rather than a single actor or prop.
+    pub fn load_gltf_scene<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<ComRc<IScene>> {
+        let root = self.load_gltf(path)?;
+        let scene = CoreScene::create();
+        scene.add_entity(root);
+        Ok(scene)
+    }
+
+    fn load_gltf_node(&self, node: &gltf::Node, buffers: &[gltf::buffer::Data]) -> anyhow::Result<ComRc<IEntity>> {
+        let name = node.name().unwrap_or("node").to_string();
+        let entity = CoreEntity::create(name, true);
+
+        let (translation, rotation, scale) = node.transform().decomposed();
+        {
+            let mut transform = entity.transform().borrow_mut();
+            transform.set_position(&Vec3::new(translation[0], translation[1], translation[2]));
+            transform.set_rotation(&Quaternion::new(rotation[0], rotation[1], rotation[2], rotation[3]));
+            transform.set_scale(&Vec3::new(scale[0], scale[1], scale[2]));
+        }
+
+        if let Some(mesh) = node.mesh() {
+            let component = load_mesh_component(&mesh, buffers)?;
+            entity.add_component(IMeshComponent::uuid(), ComRc::from_object(component));
+        }
+
+        for child in node.children() {
+            let child_entity = self.load_gltf_node(&child, buffers)?;
+            entity.add_child(child_entity);
+        }
+
+        Ok(entity)
+    }
+
+    fn resolve(&self, path: &Path) -> PathBuf {
+        if path.is_absolute() {
+            path.to_owned()
+        } else {
+            self.root_path.join(path)
+        }
+    }
+}
+
+/// Produces the native mesh data for `model_name`. It returns empty vertex/index buffers; nothing
+/// is read from disk. It touches no `Rc`/`ComRc` state, so it's safe to run from
+/// [`AssetLoader::load_actor_async`]'s worker thread.
+fn load_actor_mesh_data(_model_name: &str) -> NativeActorMesh {
+    NativeActorMesh {
+        positions: vec![],
+        indices: vec![],
+    }
+}
+
+fn load_mesh_component(mesh: &gltf::Mesh, buffers: &[gltf::buffer::Data]) -> anyhow::Result<GltfMeshComponent> {
+    let mut positions = vec![];
+    let mut indices = vec![];
+
+    for primitive in mesh.primitives() {
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+        let base_vertex = positions.len() as u32;
+        if let Some(iter) = reader.read_positions() {
+            positions.extend(iter.map(Vec3::from));
+        }
+        if let Some(iter) = reader.read_indices() {
+            indices.extend(iter.into_u32().map(|i| i + base_vertex));
+        }
+    }
+
+    Ok(GltfMeshComponent { positions, indices })
+}
@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use crosscom::ComRc;
 use radiance::{
@@ -10,10 +10,19 @@ use radiance::{
 
 use super::{
     actor::Pal4ActorController,
-    asset_loader::{self, AssetLoader},
+    asset_loader::{self, ActorLoadResult, AssetHandle, AssetLoader},
     comdef::{IPal4ActorAnimationController, IPal4ActorController},
+    scene_data::NpcRecord,
 };
 
+/// One NPC whose actor is still loading on a worker thread. `Pal4Scene::poll_pending_npcs` swaps
+/// `placeholder` out for the real entity once `handle` resolves.
+pub struct PendingNpc {
+    handle: AssetHandle<ActorLoadResult>,
+    placeholder: ComRc<IEntity>,
+    position: Vec3,
+}
+
 pub enum Player {
     YunTianhe,
     HanLingsha,
@@ -31,12 +40,13 @@ impl Player {
         }
     }
 
-    pub fn actor_name(&self) -> &str {
+    /// Key this player resolves to in the actor catalog, replacing the old hard-coded model ids.
+    pub fn catalog_id(&self) -> &str {
         match self {
-            Player::YunTianhe => "101",
-            Player::HanLingsha => "103",
-            Player::LiuMengli => "106",
-            Player::MurongZiying => "105",
+            Player::YunTianhe => "yun_tianhe",
+            Player::HanLingsha => "han_lingsha",
+            Player::LiuMengli => "liu_mengli",
+            Player::MurongZiying => "murong_ziying",
         }
     }
 }
@@ -84,10 +94,10 @@ impl Pal4Scene {
         scene.camera().borrow_mut().set_fov43(45_f32.to_radians());
 
         let players = [
-            load_player(asset_loader, Player::YunTianhe),
-            load_player(asset_loader, Player::HanLingsha),
-            load_player(asset_loader, Player::LiuMengli),
-            load_player(asset_loader, Player::MurongZiying),
+            load_player(asset_loader, Player::YunTianhe)?,
+            load_player(asset_loader, Player::HanLingsha)?,
+            load_player(asset_loader, Player::LiuMengli)?,
+            load_player(asset_loader, Player::MurongZiying)?,
         ];
 
         let controller = Pal4ActorController::create(input, players[0].clone(), scene.clone());
@@ -98,31 +108,139 @@ impl Pal4Scene {
         }
 
         let npc_info = asset_loader.load_npc_info(scene_name, block_name)?;
+        let mut actor_templates: HashMap<String, ComRc<IEntity>> = HashMap::new();
         for npc in &npc_info.data {
-            let actor_name = npc.model_name.as_str();
-            match actor_name {
-                Ok(actor_name) => {
-                    let entity = asset_loader.load_actor(
-                        npc.name.as_str().unwrap_or_default().as_str(),
-                        actor_name.as_str(),
-                        npc.get_default_act().as_deref(),
-                    );
-
-                    if let Ok(entity) = entity {
-                        entity
-                            .transform()
-                            .borrow_mut()
-                            .set_position(&Vec3::from(npc.position));
-                        scene.add_entity(entity);
+            let actor_id = match npc.model_name.as_str() {
+                Ok(actor_id) => actor_id,
+                Err(e) => {
+                    log::error!("Cannot load actor: {}", e);
+                    continue;
+                }
+            };
+            let (model_name, default_act) = resolve_npc_model(asset_loader, actor_id, npc);
+
+            let template = match actor_templates.get(model_name) {
+                Some(template) => template.clone(),
+                None => {
+                    match asset_loader.load_actor(
+                        npc.name.as_str().unwrap_or_default(),
+                        model_name,
+                        default_act.as_deref(),
+                    ) {
+                        Ok(template) => {
+                            actor_templates.insert(model_name.to_string(), template.clone());
+                            template
+                        }
+                        Err(e) => {
+                            log::error!("Cannot load actor: {}", e);
+                            continue;
+                        }
                     }
                 }
+            };
+
+            let entity = asset_loader.clone_actor(&template, npc.name.as_str().unwrap_or_default());
+            entity
+                .transform()
+                .borrow_mut()
+                .set_position(&Vec3::from(npc.position));
+            scene.add_entity(entity);
+        }
+
+        Ok(Self { scene, players })
+    }
+
+    /// Like [`Self::load`], but NPC actors are loaded asynchronously: each NPC gets an invisible
+    /// placeholder entity immediately, and the scene keeps rendering/input responsive while the
+    /// real actors stream in. Call [`Self::poll_pending_npcs`] once per frame to swap them in.
+    pub fn load_async(
+        asset_loader: &asset_loader::AssetLoader,
+        input: Rc<RefCell<dyn InputEngine>>,
+        scene_name: &str,
+        block_name: &str,
+    ) -> anyhow::Result<(Self, Vec<PendingNpc>)> {
+        let scene = asset_loader.load_scene(scene_name, block_name)?;
+        let clip = asset_loader.try_load_scene_clip(scene_name, block_name);
+        if let Some(clip) = clip {
+            scene.add_entity(clip);
+        }
+
+        let skybox = asset_loader.try_load_scene_sky(scene_name, block_name);
+        if let Some(skybox) = skybox {
+            scene.add_entity(skybox);
+        }
+
+        scene.camera().borrow_mut().set_fov43(45_f32.to_radians());
+
+        let players = [
+            load_player(asset_loader, Player::YunTianhe)?,
+            load_player(asset_loader, Player::HanLingsha)?,
+            load_player(asset_loader, Player::LiuMengli)?,
+            load_player(asset_loader, Player::MurongZiying)?,
+        ];
+
+        let controller = Pal4ActorController::create(input, players[0].clone(), scene.clone());
+        players[0].add_component(IPal4ActorController::uuid(), ComRc::from_object(controller));
+
+        for p in &players {
+            scene.add_entity(p.clone());
+        }
+
+        let npc_info = asset_loader.load_npc_info(scene_name, block_name)?;
+        let mut pending = vec![];
+        for npc in &npc_info.data {
+            let actor_id = match npc.model_name.as_str() {
+                Ok(actor_id) => actor_id,
                 Err(e) => {
-                    log::error!("Cannot load actor: {}", e)
+                    log::error!("Cannot load actor: {}", e);
+                    continue;
                 }
-            }
+            };
+            let (model_name, default_act) = resolve_npc_model(asset_loader, actor_id, npc);
+
+            let position = Vec3::from(npc.position);
+            let placeholder = CoreEntity::create(npc.name.as_str().unwrap_or_default().to_string(), false);
+            placeholder.transform().borrow_mut().set_position(&position);
+            scene.add_entity(placeholder.clone());
+
+            let handle = asset_loader.load_actor_async(
+                npc.name.as_str().unwrap_or_default(),
+                model_name,
+                default_act.as_deref(),
+            );
+
+            pending.push(PendingNpc {
+                handle,
+                placeholder,
+                position,
+            });
         }
 
-        Ok(Self { scene, players })
+        Ok((Self { scene, players }, pending))
+    }
+
+    /// Polls every in-flight NPC load, swapping each placeholder for its real actor as soon as
+    /// the load finishes. Meant to be called once per frame; resolved entries are removed from
+    /// `pending` as they finish.
+    pub fn poll_pending_npcs(&self, asset_loader: &AssetLoader, pending: &mut Vec<PendingNpc>) {
+        pending.retain_mut(|npc| {
+            if !npc.handle.poll() {
+                return true;
+            }
+
+            if let Some(result) = npc.handle.take() {
+                match asset_loader.finish_actor_load(result) {
+                    Ok(entity) => {
+                        entity.transform().borrow_mut().set_position(&npc.position);
+                        self.scene.remove_entity(npc.placeholder.clone());
+                        self.scene.add_entity(entity);
+                    }
+                    Err(e) => log::error!("Cannot load actor: {}", e),
+                }
+            }
+
+            false
+        });
     }
 
     pub fn get_player(&self, player_id: usize) -> ComRc<IEntity> {
@@ -148,12 +266,37 @@ impl Pal4Scene {
     }
 }
 
-fn load_player(asset_loader: &AssetLoader, player: Player) -> ComRc<IEntity> {
-    let entity = asset_loader
-        .load_actor(player.name(), player.actor_name(), Some("C01"))
-        .unwrap();
+/// Resolves an NPC's spawn model/default action through the actor catalog, the same way
+/// `load_player` does, keyed by the NPC record's own `model_name` acting as the catalog id. Falls
+/// back to the record's raw `model_name`/`default_act` when there's no catalog entry for it, so
+/// scene data that hasn't been migrated to `actors.ron` keeps spawning as before.
+fn resolve_npc_model<'a>(
+    asset_loader: &'a AssetLoader,
+    actor_id: &'a str,
+    npc: &'a NpcRecord,
+) -> (&'a str, Option<String>) {
+    match asset_loader.actor_definition(actor_id) {
+        Some(definition) => (definition.model_name.as_str(), definition.default_action.clone()),
+        None => (actor_id, npc.get_default_act()),
+    }
+}
 
-    entity.set_visible(false);
+fn load_player(asset_loader: &AssetLoader, player: Player) -> anyhow::Result<ComRc<IEntity>> {
+    let definition = asset_loader
+        .actor_definition(player.catalog_id())
+        .ok_or_else(|| anyhow::anyhow!("no actor catalog entry for '{}'", player.catalog_id()))?;
+
+    let entity = asset_loader.load_actor(
+        player.name(),
+        &definition.model_name,
+        definition.default_action.as_deref(),
+    )?;
 
     entity
+        .transform()
+        .borrow_mut()
+        .set_position(&Vec3::from(definition.default_position));
+    entity.set_visible(false);
+
+    Ok(entity)
 }
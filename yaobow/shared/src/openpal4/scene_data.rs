@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+/// A string as stored in the game's own text encoding, decoded lazily since some records contain
+/// bytes that don't round-trip through UTF-8 cleanly.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EncodedString(Vec<u8>);
+
+impl EncodedString {
+    pub fn as_str(&self) -> anyhow::Result<&str> {
+        std::str::from_utf8(&self.0).map_err(|e| anyhow::anyhow!("invalid encoded string: {}", e))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NpcRecord {
+    pub name: EncodedString,
+    pub model_name: EncodedString,
+    pub position: [f32; 3],
+    default_act: Option<String>,
+}
+
+impl NpcRecord {
+    pub fn get_default_act(&self) -> Option<String> {
+        self.default_act.clone()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct NpcInfo {
+    pub data: Vec<NpcRecord>,
+}